@@ -94,6 +94,10 @@ func main() {
 
 		mu.Start(0, 0x5ead0004)
 		SyncRegs(mu, ram)
+
+		if _, stats := os.LookupEnv("STATS"); stats {
+			PrintStats()
+		}
 	}
 
 	if target == -1 {
@@ -7,6 +7,7 @@ import (
 	"io/ioutil"
 	"log"
 	"os"
+	"sort"
 
 	"github.com/ethereum/go-ethereum/common"
 	"github.com/fatih/color"
@@ -23,6 +24,10 @@ func check(err error) {
 var steps int = 0
 var heap_start uint64 = 0
 
+// run statistics, printed by PrintStats
+var syscall_counts = make(map[uint64]int)
+var preimage_bytes int = 0
+
 func WriteBytes(fd int, bytes []byte) {
 	printer := color.New(color.FgWhite).SprintFunc()
 	if fd == 1 {
@@ -80,6 +85,7 @@ func GetHookedUnicorn(root string, ram map[uint32](uint32), callback func(int, u
 			log.Fatal("invalid interrupt ", intno, " at step ", steps)
 		}
 		syscall_no, _ := mu.RegRead(uc.MIPS_REG_V0)
+		syscall_counts[syscall_no] += 1
 		v0 := uint64(0)
 		if syscall_no == 4020 {
 			oracle_hash, _ := mu.MemRead(0x30001000, 0x20)
@@ -87,6 +93,7 @@ func GetHookedUnicorn(root string, ram map[uint32](uint32), callback func(int, u
 			key := fmt.Sprintf("%s/%s", root, hash)
 			value, err := ioutil.ReadFile(key)
 			check(err)
+			preimage_bytes += len(value)
 
 			tmp := []byte{0, 0, 0, 0}
 			binary.BigEndian.PutUint32(tmp, uint32(len(value)))
@@ -165,6 +172,19 @@ func GetHookedUnicorn(root string, ram map[uint32](uint32), callback func(int, u
 	return mu
 }
 
+func PrintStats() {
+	fmt.Printf("steps: %d\n", steps)
+	fmt.Printf("preimage bytes: %d\n", preimage_bytes)
+	nos := make([]uint64, 0, len(syscall_counts))
+	for no := range syscall_counts {
+		nos = append(nos, no)
+	}
+	sort.Slice(nos, func(i, j int) bool { return nos[i] < nos[j] })
+	for _, no := range nos {
+		fmt.Printf("syscall %d: %d\n", no, syscall_counts[no])
+	}
+}
+
 func LoadMappedFileUnicorn(mu uc.Unicorn, fn string, ram map[uint32](uint32), base uint32) {
 	dat, err := ioutil.ReadFile(fn)
 	check(err)